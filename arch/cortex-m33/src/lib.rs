@@ -10,9 +10,14 @@
 
 use core::fmt::Write;
 
-pub mod mpu {
-    pub type MPU = cortexm::mpu::MPU<8, 32>;
-}
+pub mod mpu;
+pub mod sau;
+
+/// The SAU instance [`call_nonsecure`] checks an entry point against before
+/// ever branching to it, so a stray or misconfigured address can't be used
+/// to jump into secure memory under the guise of a non-secure-callable
+/// gateway.
+static SAU: sau::Sau = sau::Sau::new();
 
 pub use cortexm::dwt;
 pub use cortexm::initialize_ram_jump_to_main;
@@ -40,6 +45,11 @@ impl cortexm::CortexMVariant for CortexM33 {
         user_stack: *const usize,
         process_regs: &mut [usize; 8],
     ) -> *const usize {
+        // Every process still runs secure: a `BLXNS` call/return is not a
+        // substitute for `switch_to_user_arm_v8m`'s register save/restore
+        // and exception-return machinery, so it cannot stand in here. See
+        // [`call_nonsecure`] for the (separate, synchronous) mechanism
+        // TrustZone-M support actually provides today.
         cortexv8m::switch_to_user_arm_v8m(user_stack, process_regs)
     }
 
@@ -60,3 +70,49 @@ impl cortexm::CortexMVariant for CortexM33 {
 pub mod syscall {
     pub type SysCall = cortexm::syscall::SysCall<crate::CortexM33>;
 }
+
+/// Calls into a non-secure-world function via a secure gateway (`SG`)
+/// instruction placed at `nonsecure_entry`, using `BLXNS`, and returns once
+/// the callee returns via `BXNS`.
+///
+/// This is a synchronous call/return, not a context switch: it has none of
+/// `switch_to_user_arm_v8m`'s register save/restore or exception-return
+/// handling, so it cannot be used to run a scheduled Tock process in the
+/// non-secure state (see [`call_nonsecure`] for the validated, safe-to-call
+/// wrapper other code should use instead of calling this directly).
+///
+/// # Safety
+///
+/// `nonsecure_entry` must be a valid, non-secure-callable entry point, and
+/// the non-secure stack that the callee will use must already be set up in
+/// `MSP_NS`/`PSP_NS`.
+#[cfg(all(target_arch = "arm", target_os = "none"))]
+#[inline(never)]
+pub unsafe fn branch_to_nonsecure(nonsecure_entry: usize) {
+    use core::arch::asm;
+    asm!("blxns {0}", in(reg) nonsecure_entry, options(nostack));
+}
+
+#[cfg(not(all(target_arch = "arm", target_os = "none")))]
+pub unsafe fn branch_to_nonsecure(_nonsecure_entry: usize) {
+    unimplemented!()
+}
+
+/// Validates `entry` against the SAU before calling into it via
+/// [`branch_to_nonsecure`], returning `false` instead of branching if `entry`
+/// does not currently fall within an enabled non-secure-callable region.
+///
+/// Like `branch_to_nonsecure`, this is a synchronous call that returns once
+/// the non-secure callee does -- it is not a process-switch primitive.
+///
+/// # Safety
+///
+/// The non-secure stack the callee will use must already be set up in
+/// `MSP_NS`/`PSP_NS`.
+pub unsafe fn call_nonsecure(entry: usize) -> bool {
+    if !SAU.is_nonsecure_callable(entry, SAU.num_regions()) {
+        return false;
+    }
+    branch_to_nonsecure(entry);
+    true
+}
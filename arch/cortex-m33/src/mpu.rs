@@ -0,0 +1,91 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Secure and non-secure MPU views for ARMv8-M.
+//!
+//! ARMv8-M's MPU is banked: the secure and non-secure worlds each see their
+//! own independent set of MPU registers at the same address, with the
+//! processor transparently selecting the active bank based on the current
+//! security state. Secure code can also reach the non-secure bank directly,
+//! without changing security state, through a fixed alias address range --
+//! this is what lets the kernel, which always runs secure, provision MPU
+//! regions for the non-secure world before ever calling into it via
+//! [`crate::call_nonsecure`]. Tock processes themselves always run secure;
+//! nothing here runs a process in the non-secure state.
+
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{register_bitfields, register_structs, ReadWrite};
+use kernel::utilities::StaticRef;
+
+/// The secure-world MPU.
+///
+/// This type is always the bank for whichever security state is current
+/// when it is accessed, which for the kernel (which never itself drops to
+/// the non-secure world) is always the secure bank.
+pub type MPU = cortexm::mpu::MPU<8, 32>;
+
+register_bitfields![u32,
+    RBAR [
+        BADDR OFFSET(5) NUMBITS(27) []
+    ],
+    RLAR [
+        ENABLE OFFSET(0) NUMBITS(1) [],
+        LADDR OFFSET(5) NUMBITS(27) []
+    ]
+];
+
+register_structs! {
+    NonSecureMpuRegisters {
+        (0x00 => _reserved0),
+        (0x08 => rnr: ReadWrite<u32>),
+        (0x0c => rbar: ReadWrite<u32, RBAR::Register>),
+        (0x10 => rlar: ReadWrite<u32, RLAR::Register>),
+        (0x14 => @END),
+    }
+}
+
+// The non-secure MPU alias: the same register layout as the banked MPU at
+// 0xe000ed90, offset +0x2_0000 so it always targets the non-secure bank
+// regardless of the processor's current security state.
+const NS_MPU_BASE_ADDRESS: usize = 0xe002_ed90;
+const NS_MPU_REGISTERS: StaticRef<NonSecureMpuRegisters> =
+    unsafe { StaticRef::new(NS_MPU_BASE_ADDRESS as *const NonSecureMpuRegisters) };
+
+/// A view onto the non-secure world's banked MPU regions, reachable from
+/// secure code via the fixed alias address range above without changing
+/// security state.
+///
+/// This is deliberately a thin region-configuration surface rather than a
+/// full `kernel::platform::mpu::MPU` implementation: the non-secure regions
+/// are provisioned once by the secure kernel, not reconfigured on every
+/// context switch the way [`MPU`] is.
+pub struct NonSecureMPU {
+    registers: StaticRef<NonSecureMpuRegisters>,
+}
+
+impl NonSecureMPU {
+    pub const fn new() -> NonSecureMPU {
+        NonSecureMPU {
+            registers: NS_MPU_REGISTERS,
+        }
+    }
+
+    /// Configures non-secure MPU region `index` to cover
+    /// `[start, start + size)`.
+    ///
+    /// `start` and `start + size` must both be aligned to 32 bytes, the
+    /// MPU's region granularity.
+    pub fn configure_region(&self, index: u8, start: usize, size: usize) {
+        let end = start + size;
+        debug_assert!(start % 32 == 0 && end % 32 == 0);
+
+        self.registers.rnr.set(index as u32);
+        self.registers
+            .rbar
+            .write(RBAR::BADDR.val((start as u32) >> 5));
+        self.registers
+            .rlar
+            .write(RLAR::LADDR.val(((end - 1) as u32) >> 5) + RLAR::ENABLE::SET);
+    }
+}
@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! The ARMv8-M Security Attribution Unit (SAU).
+//!
+//! The SAU partitions the address space into secure, non-secure, and
+//! non-secure-callable (NSC) regions, giving TrustZone-M a hardware security
+//! boundary independent of (and banked separately from) the MPU. Tock uses
+//! it to mark the flash/RAM/peripheral regions the non-secure world may
+//! access as non-secure, and the small gateway veneer it calls through
+//! (containing only `SG` instructions, see [`crate::branch_to_nonsecure`])
+//! as non-secure-callable. Anything not covered by a region, and anything
+//! explicitly marked secure, is only reachable from the secure world.
+//!
+//! Addresses not covered by any enabled SAU region, and addresses covered by
+//! a disabled region, default to secure -- the SAU is deny-by-default, so
+//! leaving it entirely unconfigured is the safe (if maximally restrictive)
+//! state.
+
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{register_bitfields, register_structs, ReadOnly, ReadWrite};
+use kernel::utilities::StaticRef;
+
+register_bitfields![u32,
+    CTRL [
+        /// Enables the SAU. While disabled, every address is treated as
+        /// secure and non-secure-callable state does not exist.
+        ENABLE OFFSET(0) NUMBITS(1) [],
+        /// When the SAU is disabled, treat all memory as non-secure instead
+        /// of secure. Tock never sets this: an unconfigured SAU should fail
+        /// closed.
+        ALLNS OFFSET(1) NUMBITS(1) []
+    ],
+    TYPE [
+        /// Number of SAU regions this implementation provides. Varies by
+        /// part, so callers that scan the region list (e.g.
+        /// [`Sau::is_nonsecure_callable`]) should read this instead of
+        /// assuming a fixed count.
+        SREGION OFFSET(0) NUMBITS(8) []
+    ],
+    RNR [
+        REGION OFFSET(0) NUMBITS(8) []
+    ],
+    RBAR [
+        BADDR OFFSET(5) NUMBITS(27) []
+    ],
+    RLAR [
+        ENABLE OFFSET(0) NUMBITS(1) [],
+        /// Marks the region non-secure-callable rather than plain
+        /// non-secure. Only meaningful when the region is also covered by a
+        /// secure IDAU/SAU setting at a higher priority, per the Armv8-M
+        /// architecture reference manual.
+        NSC OFFSET(1) NUMBITS(1) [],
+        LADDR OFFSET(5) NUMBITS(27) []
+    ]
+];
+
+register_structs! {
+    SauRegisters {
+        (0x000 => ctrl: ReadWrite<u32, CTRL::Register>),
+        (0x004 => sau_type: ReadOnly<u32, TYPE::Register>),
+        (0x008 => rnr: ReadWrite<u32, RNR::Register>),
+        (0x00c => rbar: ReadWrite<u32, RBAR::Register>),
+        (0x010 => rlar: ReadWrite<u32, RLAR::Register>),
+        (0x014 => @END),
+    }
+}
+
+const SAU_BASE_ADDRESS: usize = 0xe000_edd0;
+const SAU_REGISTERS: StaticRef<SauRegisters> =
+    unsafe { StaticRef::new(SAU_BASE_ADDRESS as *const SauRegisters) };
+
+/// How an SAU region should classify the addresses it covers.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SecurityAttribute {
+    /// Only the secure world may access this region.
+    Secure,
+    /// The non-secure world may access this region, but may not call into
+    /// it directly -- only through a secure gateway veneer elsewhere.
+    NonSecure,
+    /// The non-secure world may call directly into this region via `SG`
+    /// instructions placed inside it; execution then transitions to secure
+    /// state.
+    NonSecureCallable,
+}
+
+pub struct Sau {
+    registers: StaticRef<SauRegisters>,
+}
+
+impl Sau {
+    pub const fn new() -> Sau {
+        Sau {
+            registers: SAU_REGISTERS,
+        }
+    }
+
+    pub fn enable(&self) {
+        self.registers.ctrl.write(CTRL::ENABLE::SET);
+    }
+
+    pub fn disable(&self) {
+        self.registers.ctrl.write(CTRL::ENABLE::CLEAR);
+    }
+
+    /// Returns the number of SAU regions this implementation provides.
+    pub fn num_regions(&self) -> u8 {
+        self.registers.sau_type.read(TYPE::SREGION) as u8
+    }
+
+    /// Configures SAU region `index` to cover `[start, start + size)` with
+    /// the given `attribute`.
+    ///
+    /// `start` and `start + size` must both be aligned to 32 bytes, the
+    /// SAU's region granularity.
+    pub fn configure_region(
+        &self,
+        index: u8,
+        start: usize,
+        size: usize,
+        attribute: SecurityAttribute,
+    ) {
+        let end = start + size;
+        debug_assert!(start % 32 == 0 && end % 32 == 0);
+
+        self.registers.rnr.write(RNR::REGION.val(index as u32));
+        self.registers
+            .rbar
+            .write(RBAR::BADDR.val((start as u32) >> 5));
+
+        let enable = RLAR::ENABLE::SET;
+        let nsc = match attribute {
+            SecurityAttribute::Secure => {
+                // A secure region is simply never enabled: addresses not
+                // covered by any enabled region default to secure.
+                self.registers.rlar.write(RLAR::ENABLE::CLEAR);
+                return;
+            }
+            SecurityAttribute::NonSecure => RLAR::NSC::CLEAR,
+            SecurityAttribute::NonSecureCallable => RLAR::NSC::SET,
+        };
+        self.registers
+            .rlar
+            .write(RLAR::LADDR.val(((end - 1) as u32) >> 5) + enable + nsc);
+    }
+
+    /// Returns whether `addr` currently falls within an enabled
+    /// non-secure-callable region, by scanning the (small) region list.
+    ///
+    /// Used to validate a gateway entry point before branching to it, rather
+    /// than trusting the caller blindly.
+    pub fn is_nonsecure_callable(&self, addr: usize, num_regions: u8) -> bool {
+        for index in 0..num_regions {
+            self.registers.rnr.write(RNR::REGION.val(index as u32));
+            if !self.registers.rlar.is_set(RLAR::ENABLE) {
+                continue;
+            }
+            if !self.registers.rlar.is_set(RLAR::NSC) {
+                continue;
+            }
+            let base = (self.registers.rbar.read(RBAR::BADDR) as usize) << 5;
+            let limit = ((self.registers.rlar.read(RLAR::LADDR) as usize) << 5) | 0x1f;
+            if addr >= base && addr <= limit {
+                return true;
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,24 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Shared implementations for the RP2350's Hazard3 RISC-V cores.
+
+#![crate_name = "hazard3"]
+#![crate_type = "rlib"]
+#![no_std]
+
+pub mod xh3irq;
+
+pub mod pmp {
+    pub type PMP = rv32i::pmp::PMP<8>;
+}
+
+pub mod syscall {
+    pub type SysCall = rv32i::syscall::SysCall;
+}
+
+pub use rv32i::initialize_ram_jump_to_main;
+pub use rv32i::print_riscv_state;
+pub use rv32i::support;
+pub use rv32i::unhandled_interrupt;
@@ -0,0 +1,126 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Driver for the Hazard3 external interrupt controller ("Xh3irq").
+//!
+//! Unlike most RISC-V cores, Hazard3 does not route external interrupts
+//! through a PLIC. Instead it exposes them through a handful of custom
+//! machine-mode CSRs:
+//!
+//! - `meiea` ("machine external interrupt enable array") selects an 8-IRQ
+//!   wide window and exposes that window's enable/priority bits.
+//! - `meipa` ("machine external interrupt pending array") exposes the
+//!   pending bits for the window currently selected in `meiea`.
+//! - `meinext` ("machine external interrupt next") atomically returns the
+//!   index of the highest-priority pending external interrupt, with a
+//!   sentinel value in its most-significant bit when no interrupt is
+//!   pending. Writing it with the "update" bit set also re-selects `meiea`
+//!   and `meipa` to the window containing that index, and can be configured
+//!   to clear the interrupt as it is read.
+//!
+//! This module provides safe-ish wrappers around those CSRs so the chip
+//! crate can dispatch external interrupts without hand-rolling the
+//! windowing logic at every call site.
+
+use core::arch::asm;
+
+/// Number of external interrupt lines exposed through one `meiea`/`meipa`
+/// window.
+const IRQS_PER_WINDOW: u32 = 8;
+
+/// Set in `meinext` when no external interrupt is pending.
+const MEINEXT_NOIRQ: usize = 1 << (usize::BITS - 1);
+
+/// Requests `meinext` to advance `meiea`/`meipa` to the returned IRQ's
+/// window and to clear that IRQ as it is read.
+const MEINEXT_UPDATE_CLEAR: usize = 0b11;
+
+/// `meiea`, `meipa`, and `meinext` are non-standard CSRs with no symbolic
+/// names the assembler understands, so they must be addressed by their raw
+/// numbers (see the Hazard3 documentation). `$csr` below must be a numeric
+/// literal, not a named `const` -- `stringify!` on an identifier would emit
+/// that identifier's text, not the value it holds, producing an invalid
+/// asm operand.
+macro_rules! read_csr {
+    ($csr:literal) => {{
+        let r: usize;
+        asm!(concat!("csrr {0}, ", stringify!($csr)), out(reg) r, options(nomem, nostack));
+        r
+    }};
+}
+
+macro_rules! write_csr {
+    ($csr:literal, $val:expr) => {{
+        asm!(concat!("csrw ", stringify!($csr), ", {0}"), in(reg) $val, options(nomem, nostack));
+    }};
+}
+
+/// Returns the `meiea`/`meipa` window containing `irq` and the bit index of
+/// `irq` within that window.
+fn window_and_bit(irq: u32) -> (u32, u32) {
+    (irq / IRQS_PER_WINDOW, irq % IRQS_PER_WINDOW)
+}
+
+/// A single external interrupt line managed through `meiea`/`meipa`.
+pub struct Xh3irq(u32);
+
+impl Xh3irq {
+    pub const fn new(irq: u32) -> Xh3irq {
+        Xh3irq(irq)
+    }
+
+    /// Enables this interrupt in `meiea`.
+    pub fn enable(&self) {
+        let (window, bit) = window_and_bit(self.0);
+        unsafe {
+            write_csr!(0xbe0, window as usize); // meiea
+            let enables = read_csr!(0xbe0); // meiea
+            write_csr!(0xbe0, enables | (1 << bit)); // meiea
+        }
+    }
+
+    /// Disables this interrupt in `meiea`.
+    pub fn disable(&self) {
+        let (window, bit) = window_and_bit(self.0);
+        unsafe {
+            write_csr!(0xbe0, window as usize); // meiea
+            let enables = read_csr!(0xbe0); // meiea
+            write_csr!(0xbe0, enables & !(1 << bit)); // meiea
+        }
+    }
+
+    /// Returns whether this interrupt is currently pending in `meipa`.
+    pub fn is_pending(&self) -> bool {
+        let (window, bit) = window_and_bit(self.0);
+        unsafe {
+            write_csr!(0xbe0, window as usize); // meiea
+            read_csr!(0xbe1) & (1 << bit) != 0 // meipa
+        }
+    }
+}
+
+/// Returns the index of the highest-priority pending external interrupt, if
+/// any, and clears it as it is read.
+///
+/// This is the primary entry point for the `MachineExternal` trap handler:
+/// it should call this in a loop, dispatching each returned index, until it
+/// returns `None`.
+pub fn next_pending() -> Option<u32> {
+    let val = unsafe {
+        write_csr!(0xbe4, MEINEXT_UPDATE_CLEAR); // meinext
+        read_csr!(0xbe4) // meinext
+    };
+    if val & MEINEXT_NOIRQ != 0 {
+        None
+    } else {
+        Some(val as u32)
+    }
+}
+
+/// Returns whether any external interrupt is currently pending, without
+/// clearing it.
+pub fn has_pending() -> bool {
+    let val = unsafe { read_csr!(0xbe4) }; // meinext
+    val & MEINEXT_NOIRQ == 0
+}
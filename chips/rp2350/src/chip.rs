@@ -5,6 +5,7 @@
 //! Chip trait setup.
 
 use core::fmt::Write;
+use kernel::hil::time::Alarm;
 use kernel::platform::chip::Chip;
 use kernel::platform::chip::InterruptService;
 
@@ -14,9 +15,12 @@ use crate::gpio::{RPGpio, RPPins, SIO};
 use crate::i2c;
 use crate::interrupts;
 use crate::pio::Pio;
+use crate::powman::{Powman, SleepState};
 use crate::pwm;
+use crate::pwm_irq::PwmIrq;
 use crate::resets::Resets;
 use crate::rtc;
+use crate::rtc_datetime::RtcDateTime;
 use crate::spi;
 use crate::sysinfo;
 use crate::timer::RPTimer;
@@ -37,17 +41,48 @@ pub struct Rp2350<'a, I: InterruptService + 'a> {
     userspace_kernel_boundary: cortexm33::syscall::SysCall,
     interrupt_service: &'a I,
     sio: &'a SIO,
+    timer: &'a RPTimer<'a>,
+    powman: &'a Powman<'a>,
     processor0_interrupt_mask: (u128, u128),
     processor1_interrupt_mask: (u128, u128),
 }
 
+// The 4 KiB "scratch Y" SRAM bank, unused by the secure kernel, carved out
+// as the one region a non-secure world may access. Mirrored into both the
+// SAU and the non-secure MPU so a non-secure call target has a stack to
+// run on; nothing in this tree transitions to the non-secure world yet,
+// but the hardware state needs to exist before anything can.
+const NONSECURE_SCRATCH_BASE: usize = 0x2008_0000;
+const NONSECURE_SCRATCH_SIZE: usize = 0x1000;
+
 impl<'a, I: InterruptService> Rp2350<'a, I> {
-    pub unsafe fn new(interrupt_service: &'a I, sio: &'a SIO) -> Self {
+    pub unsafe fn new(
+        interrupt_service: &'a I,
+        sio: &'a SIO,
+        timer: &'a RPTimer<'a>,
+        powman: &'a Powman<'a>,
+    ) -> Self {
+        let sau = cortexm33::sau::Sau::new();
+        sau.configure_region(
+            0,
+            NONSECURE_SCRATCH_BASE,
+            NONSECURE_SCRATCH_SIZE,
+            cortexm33::sau::SecurityAttribute::NonSecure,
+        );
+        sau.enable();
+        cortexm33::mpu::NonSecureMPU::new().configure_region(
+            0,
+            NONSECURE_SCRATCH_BASE,
+            NONSECURE_SCRATCH_SIZE,
+        );
+
         Self {
             mpu: cortexm33::mpu::MPU::new(),
             userspace_kernel_boundary: cortexm33::syscall::SysCall::new(),
             interrupt_service,
             sio,
+            timer,
+            powman,
             processor0_interrupt_mask: interrupt_mask!(interrupts::SIO_IRQ_PROC1),
             processor1_interrupt_mask: interrupt_mask!(interrupts::SIO_IRQ_PROC0),
         }
@@ -102,9 +137,35 @@ impl<I: InterruptService> Chip for Rp2350<'_, I> {
     }
 
     fn sleep(&self) {
-        unsafe {
-            cortexm33::support::wfi();
-        }
+        // Pick the deepest state still compatible with the next pending
+        // kernel timer deadline: if nothing is armed there is no reason to
+        // wake early, so we can gate clocks; if something is armed, convert
+        // the remaining ticks in the kernel timer's (wrapping, 32-bit,
+        // microsecond) domain into a delay and arm the AON timer -- which
+        // free-runs in its own 48-bit microsecond domain -- for that same
+        // delay before gating them.
+        let state = if self.timer.is_armed() {
+            let now = self.timer.now().into_u32();
+            let deadline = self.timer.get_alarm().into_u32();
+            // `deadline.wrapping_sub(now)` only means "ticks remaining" if
+            // the deadline is genuinely still ahead of `now`; if it has
+            // already passed (e.g. it fires right as we make this check,
+            // before the IRQ is serviced), the same wrapping subtraction
+            // instead yields a value near `u32::MAX`, which would arm the
+            // AON timer for a ~71-minute wrap-around delay instead of
+            // waking immediately. Interpreting the difference as signed
+            // distinguishes the two: a negative (or zero) result means the
+            // deadline is already due, so don't sleep deeper than `Wfi`.
+            let remaining_ticks = deadline.wrapping_sub(now) as i32;
+            if remaining_ticks <= 0 {
+                SleepState::Wfi
+            } else {
+                SleepState::TimedLowPower(remaining_ticks as u64)
+            }
+        } else {
+            SleepState::Dormant
+        };
+        self.powman.sleep(state);
     }
 
     unsafe fn atomic<F, R>(&self, f: F) -> R
@@ -119,6 +180,88 @@ impl<I: InterruptService> Chip for Rp2350<'_, I> {
     }
 }
 
+/// The RISC-V (Hazard3) personality of the RP2350.
+///
+/// The RP2350 ships two Cortex-M33 cores and two Hazard3 RISC-V cores, only
+/// one architecture of which is active at a time (selected by the boot ROM
+/// based on `OTP`/boot configuration). This mirrors [`Rp2350`] but drives the
+/// MPU, syscall boundary, and interrupt controller through the RISC-V core's
+/// `hazard3` crate instead of `cortexm33`. The two share the same
+/// [`Rp2350DefaultPeripherals`], so peripheral drivers do not need to be
+/// duplicated between the two builds.
+pub struct Rp2350Riscv<'a, I: InterruptService + 'a> {
+    mpu: hazard3::pmp::PMP,
+    userspace_kernel_boundary: hazard3::syscall::SysCall,
+    interrupt_service: &'a I,
+}
+
+impl<'a, I: InterruptService> Rp2350Riscv<'a, I> {
+    pub unsafe fn new(interrupt_service: &'a I) -> Self {
+        Self {
+            mpu: hazard3::pmp::PMP::new(),
+            userspace_kernel_boundary: hazard3::syscall::SysCall::new(),
+            interrupt_service,
+        }
+    }
+}
+
+impl<I: InterruptService> Chip for Rp2350Riscv<'_, I> {
+    type MPU = hazard3::pmp::PMP;
+    type UserspaceKernelBoundary = hazard3::syscall::SysCall;
+
+    fn service_pending_interrupts(&self) {
+        unsafe {
+            handle_machine_external_interrupt(self.interrupt_service);
+        }
+    }
+
+    fn has_pending_interrupts(&self) -> bool {
+        hazard3::xh3irq::has_pending()
+    }
+
+    fn mpu(&self) -> &Self::MPU {
+        &self.mpu
+    }
+
+    fn userspace_kernel_boundary(&self) -> &Self::UserspaceKernelBoundary {
+        &self.userspace_kernel_boundary
+    }
+
+    fn sleep(&self) {
+        unsafe {
+            hazard3::support::wfi();
+        }
+    }
+
+    unsafe fn atomic<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        hazard3::support::atomic(f)
+    }
+
+    unsafe fn print_state(&self, writer: &mut dyn Write) {
+        hazard3::print_riscv_state(writer);
+    }
+}
+
+/// The `MachineExternal` trap handler for the RISC-V personality.
+///
+/// Hazard3 has no PLIC to claim/complete against; instead `meinext` hands
+/// back the index of the highest-priority pending external interrupt (and
+/// clears it) one at a time, so this drains it in a loop, dispatching each
+/// index through the same [`InterruptService::service_interrupt`] path the
+/// Cortex-M33 build uses.
+pub unsafe fn handle_machine_external_interrupt<I: InterruptService>(
+    interrupt_service: &I,
+) {
+    while let Some(interrupt) = hazard3::xh3irq::next_pending() {
+        if !interrupt_service.service_interrupt(interrupt) {
+            panic!("unhandled interrupt {}", interrupt);
+        }
+    }
+}
+
 pub struct Rp2350DefaultPeripherals<'a> {
     pub adc: adc::Adc<'a>,
     pub clocks: Clocks,
@@ -126,7 +269,9 @@ pub struct Rp2350DefaultPeripherals<'a> {
     pub pins: RPPins<'a>,
     pub pio0: Pio,
     pub pio1: Pio,
+    pub powman: Powman<'a>,
     pub pwm: pwm::Pwm<'a>,
+    pub pwm_irq: PwmIrq<'a>,
     pub resets: Resets,
     pub sio: SIO,
     pub spi0: spi::Spi<'a>,
@@ -138,6 +283,7 @@ pub struct Rp2350DefaultPeripherals<'a> {
     pub watchdog: Watchdog<'a>,
     pub xosc: Xosc,
     pub rtc: rtc::Rtc<'a>,
+    pub rtc_datetime: RtcDateTime<'a>,
 }
 
 impl Rp2350DefaultPeripherals<'_> {
@@ -149,7 +295,9 @@ impl Rp2350DefaultPeripherals<'_> {
             pins: RPPins::new(),
             pio0: Pio::new_pio0(),
             pio1: Pio::new_pio1(),
+            powman: Powman::new(),
             pwm: pwm::Pwm::new(),
+            pwm_irq: PwmIrq::new(),
             resets: Resets::new(),
             sio: SIO::new(),
             spi0: spi::Spi::new_spi0(),
@@ -161,11 +309,19 @@ impl Rp2350DefaultPeripherals<'_> {
             watchdog: Watchdog::new(),
             xosc: Xosc::new(),
             rtc: rtc::Rtc::new(),
+            rtc_datetime: RtcDateTime::new(),
         }
     }
 
     pub fn resolve_dependencies(&'static self) {
         self.pwm.set_clocks(&self.clocks);
+        // `pwm::Pwm` is the HIL-facing driver capsules register their wrap
+        // client with; route the shared wrap interrupt's per-slice
+        // notifications into it instead of leaving `pwm_irq` wired to
+        // nothing, and give it back a handle to `pwm_irq` so it can enable
+        // a slice's wrap interrupt when a capsule registers for it.
+        self.pwm_irq.set_client(&self.pwm);
+        self.pwm.set_pwm_irq(&self.pwm_irq);
         self.watchdog.resolve_dependencies(&self.resets);
         self.spi0.set_clocks(&self.clocks);
         self.uart0.set_clocks(&self.clocks);
@@ -175,6 +331,7 @@ impl Rp2350DefaultPeripherals<'_> {
         self.i2c0.resolve_dependencies(&self.clocks, &self.resets);
         self.usb.set_gpio(self.pins.get_pin(RPGpio::GPIO15));
         self.rtc.set_clocks(&self.clocks);
+        self.powman.set_clocks(&self.clocks);
     }
 }
 
@@ -223,10 +380,11 @@ impl InterruptService for Rp2350DefaultPeripherals<'_> {
                 true
             }
             interrupts::PWM_IRQ_WRAP => {
-                // As the PWM HIL doesn't provide any support for interrupts, they are
-                // simply ignored.
-                //
-                // Note that PWM interrupts are raised only during unit tests.
+                self.pwm_irq.handle_interrupt();
+                true
+            }
+            interrupts::RTC_IRQ => {
+                self.rtc_datetime.handle_match();
                 true
             }
             _ => false,
@@ -0,0 +1,29 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2022.
+
+//! Drivers and chip support for the RP2350.
+
+#![no_std]
+
+pub mod adc;
+pub mod chip;
+pub mod clocks;
+pub mod gpio;
+pub mod i2c;
+pub mod interrupts;
+pub mod pio;
+pub mod powman;
+pub mod pwm;
+pub mod pwm_irq;
+pub mod resets;
+pub mod rtc;
+pub mod rtc_datetime;
+pub mod sio_fifo;
+pub mod spi;
+pub mod sysinfo;
+pub mod timer;
+pub mod uart;
+pub mod usb;
+pub mod watchdog;
+pub mod xosc;
@@ -0,0 +1,161 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! POWMAN driver: low-power sleep states and always-on (AON) timer wakeup.
+//!
+//! POWMAN owns the RP2350's power domains and its always-on timer. Every
+//! write to `STATE` is ignored unless the upper byte of the write carries a
+//! fixed password, so this driver funnels those writes through
+//! [`Powman::write_state`] to make sure that key can never be forgotten at a
+//! call site.
+
+use kernel::utilities::cells::OptionalCell;
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{
+    register_bitfields, register_structs, FieldValue, ReadOnly, ReadWrite,
+};
+use kernel::utilities::StaticRef;
+
+use crate::clocks::Clocks;
+
+register_bitfields![u32,
+    STATE [
+        /// Magic value POWMAN requires in the upper byte of every write to
+        /// this register, or the write is silently dropped.
+        PASSWORD OFFSET(24) NUMBITS(8) [
+            Key = 0x5a
+        ],
+        /// Power down unused domains and gate clocks; wake on any enabled
+        /// source.
+        DORMANT OFFSET(0) NUMBITS(1) [],
+        /// Enable the always-on timer as a wake source.
+        WAKE_ON_TIMER OFFSET(1) NUMBITS(1) [],
+        /// Enable GPIO as a wake source.
+        WAKE_ON_GPIO OFFSET(2) NUMBITS(1) []
+    ],
+    ALARM_TIME [
+        TIME OFFSET(0) NUMBITS(16) []
+    ]
+];
+
+register_structs! {
+    PowmanRegisters {
+        (0x000 => _reserved0),
+        (0x01c => state: ReadWrite<u32, STATE::Register>),
+        (0x020 => _reserved1),
+        (0x048 => alarm_time_63to48: ReadWrite<u32, ALARM_TIME::Register>),
+        (0x04c => alarm_time_47to32: ReadWrite<u32, ALARM_TIME::Register>),
+        (0x050 => alarm_time_31to16: ReadWrite<u32, ALARM_TIME::Register>),
+        (0x054 => alarm_time_15to0: ReadWrite<u32, ALARM_TIME::Register>),
+        (0x058 => _reserved2),
+        (0x098 => read_time_63to48: ReadOnly<u32, ALARM_TIME::Register>),
+        (0x09c => read_time_47to32: ReadOnly<u32, ALARM_TIME::Register>),
+        (0x0a0 => read_time_31to16: ReadOnly<u32, ALARM_TIME::Register>),
+        (0x0a4 => read_time_15to0: ReadOnly<u32, ALARM_TIME::Register>),
+        (0x0a8 => @END),
+    }
+}
+
+const POWMAN_BASE_ADDRESS: usize = 0x4006_0000;
+const POWMAN_REGISTERS: StaticRef<PowmanRegisters> =
+    unsafe { StaticRef::new(POWMAN_BASE_ADDRESS as *const PowmanRegisters) };
+
+/// How deep a sleep to request. `Wfi` is the shallowest. `Dormant` and
+/// `TimedLowPower` gate the same domains; `TimedLowPower` additionally
+/// enables the AON timer and GPIO as wake sources, so `Dormant` is the
+/// deepest state.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SleepState {
+    /// Plain `wfi`; all clocks and power domains stay up.
+    Wfi,
+    /// Clock-gate unused domains; wakes on any enabled interrupt.
+    Dormant,
+    /// Clock-gate unused domains and arm the AON timer to wake after the
+    /// given number of microseconds have elapsed, in addition to any
+    /// enabled interrupt.
+    TimedLowPower(u64),
+}
+
+pub struct Powman<'a> {
+    registers: StaticRef<PowmanRegisters>,
+    clocks: OptionalCell<&'a Clocks>,
+}
+
+impl<'a> Powman<'a> {
+    pub const fn new() -> Powman<'a> {
+        Powman {
+            registers: POWMAN_REGISTERS,
+            clocks: OptionalCell::empty(),
+        }
+    }
+
+    pub fn set_clocks(&self, clocks: &'a Clocks) {
+        self.clocks.set(clocks);
+    }
+
+    /// Writes `fields` to `STATE`, folding in the password POWMAN requires
+    /// to accept the write.
+    fn write_state(&self, fields: FieldValue<u32, STATE::Register>) {
+        self.registers
+            .state
+            .write(STATE::PASSWORD.val(0x5a) + fields);
+    }
+
+    /// Reads the AON timer's free-running, never-reset 48-bit microsecond
+    /// count.
+    fn current_time(&self) -> u64 {
+        let low = self.registers.read_time_15to0.read(ALARM_TIME::TIME) as u64;
+        let mid = self.registers.read_time_31to16.read(ALARM_TIME::TIME) as u64;
+        let high = self.registers.read_time_47to32.read(ALARM_TIME::TIME) as u64;
+        low | (mid << 16) | (high << 32)
+    }
+
+    /// Arms the AON timer to fire `delay_us` microseconds from now.
+    ///
+    /// The AON timer free-runs in its own 48-bit microsecond domain that
+    /// never resets across kernel timer wraparounds, so `delay_us` must be
+    /// added to the AON timer's own current count -- callers must not pass
+    /// it an absolute deadline taken from a different clock's tick domain
+    /// (e.g. the kernel `Alarm`'s wrapping 32-bit counter).
+    fn arm_alarm(&self, delay_us: u64) {
+        let deadline = self.current_time().wrapping_add(delay_us);
+        self.registers
+            .alarm_time_15to0
+            .write(ALARM_TIME::TIME.val(deadline as u32 & 0xffff));
+        self.registers
+            .alarm_time_31to16
+            .write(ALARM_TIME::TIME.val((deadline >> 16) as u32 & 0xffff));
+        self.registers
+            .alarm_time_47to32
+            .write(ALARM_TIME::TIME.val((deadline >> 32) as u32 & 0xffff));
+        self.registers
+            .alarm_time_63to48
+            .write(ALARM_TIME::TIME.val((deadline >> 48) as u32 & 0xffff));
+    }
+
+    /// Enters `state`, restoring clocks once the core wakes back up (power
+    /// domains that were gated leave the clock tree in an indeterminate
+    /// configuration until `Clocks` reconfigures it).
+    pub fn sleep(&self, state: SleepState) {
+        match state {
+            SleepState::Wfi => {
+                unsafe { cortexm33::support::wfi() };
+                return;
+            }
+            SleepState::Dormant => {
+                self.write_state(STATE::DORMANT::SET);
+            }
+            SleepState::TimedLowPower(deadline) => {
+                self.arm_alarm(deadline);
+                self.write_state(
+                    STATE::DORMANT::SET + STATE::WAKE_ON_TIMER::SET + STATE::WAKE_ON_GPIO::SET,
+                );
+            }
+        }
+
+        unsafe { cortexm33::support::wfi() };
+
+        self.clocks.map(|clocks| clocks.configure());
+    }
+}
@@ -0,0 +1,142 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! PWM driver: per-slice frequency/duty-cycle configuration and
+//! wrap-interrupt dispatch.
+//!
+//! Each slice's register group is CSR/DIV/CTR/CC/TOP (5 registers, 0x14
+//! bytes) -- the same stride `pwm_irq` uses to find the shared interrupt
+//! block that follows the 12 slices.
+//!
+//! Writing `CC` mid-cycle produces one truncated or stretched pulse before
+//! the new duty cycle takes effect, so a capsule-requested duty-cycle
+//! change is staged and only actually written to hardware the next time
+//! that slice's counter wraps (via [`pwm_irq::Client::wrapped`]), instead of being
+//! applied immediately.
+
+use core::cell::Cell;
+
+use kernel::utilities::cells::OptionalCell;
+use kernel::utilities::registers::interfaces::Writeable;
+use kernel::utilities::registers::{register_bitfields, register_structs, ReadWrite};
+use kernel::utilities::StaticRef;
+
+use crate::clocks::Clocks;
+use crate::pwm_irq;
+
+register_bitfields![u32,
+    CSR [
+        EN OFFSET(0) NUMBITS(1) [],
+        PH_CORRECT OFFSET(1) NUMBITS(1) [],
+        A_INV OFFSET(2) NUMBITS(1) [],
+        B_INV OFFSET(3) NUMBITS(1) []
+    ],
+    DIV [
+        INT OFFSET(4) NUMBITS(8) [],
+        FRAC OFFSET(0) NUMBITS(4) []
+    ],
+    CTR [
+        CTR OFFSET(0) NUMBITS(16) []
+    ],
+    CC [
+        A OFFSET(0) NUMBITS(16) [],
+        B OFFSET(16) NUMBITS(16) []
+    ],
+    TOP [
+        TOP OFFSET(0) NUMBITS(16) []
+    ]
+];
+
+register_structs! {
+    SliceRegisters {
+        (0x00 => csr: ReadWrite<u32, CSR::Register>),
+        (0x04 => div: ReadWrite<u32, DIV::Register>),
+        (0x08 => ctr: ReadWrite<u32, CTR::Register>),
+        (0x0c => cc: ReadWrite<u32, CC::Register>),
+        (0x10 => top: ReadWrite<u32, TOP::Register>),
+        (0x14 => @END),
+    }
+}
+
+const PWM_BASE_ADDRESS: usize = 0x4009_8000;
+const SLICE_STRIDE: usize = 0x14;
+
+fn slice_registers(slice: usize) -> StaticRef<SliceRegisters> {
+    unsafe { StaticRef::new((PWM_BASE_ADDRESS + slice * SLICE_STRIDE) as *const SliceRegisters) }
+}
+
+/// Notified after a PWM slice's counter wraps (and any staged duty-cycle
+/// update for it has been applied).
+pub trait WrapClient {
+    fn wrapped(&self);
+}
+
+pub struct Pwm<'a> {
+    clocks: OptionalCell<&'a Clocks>,
+    pwm_irq: OptionalCell<&'a pwm_irq::PwmIrq<'a>>,
+    wrap_clients: [OptionalCell<&'a dyn WrapClient>; pwm_irq::NUM_SLICES],
+    // Packed `CC::A | (CC::B << 16)`, staged until the slice's next wrap.
+    pending_duty: [Cell<Option<u32>>; pwm_irq::NUM_SLICES],
+}
+
+impl<'a> Pwm<'a> {
+    pub const fn new() -> Pwm<'a> {
+        const EMPTY_CLIENT: OptionalCell<&dyn WrapClient> = OptionalCell::empty();
+        const EMPTY_DUTY: Cell<Option<u32>> = Cell::new(None);
+        Pwm {
+            clocks: OptionalCell::empty(),
+            pwm_irq: OptionalCell::empty(),
+            wrap_clients: [EMPTY_CLIENT; pwm_irq::NUM_SLICES],
+            pending_duty: [EMPTY_DUTY; pwm_irq::NUM_SLICES],
+        }
+    }
+
+    pub fn set_clocks(&self, clocks: &'a Clocks) {
+        self.clocks.set(clocks);
+    }
+
+    /// Gives this driver the shared wrap-interrupt register block, so
+    /// [`set_wrap_client`](Self::set_wrap_client) can enable a slice's wrap
+    /// interrupt on the caller's behalf instead of requiring it to reach
+    /// into `pwm_irq` itself.
+    pub fn set_pwm_irq(&self, pwm_irq: &'a pwm_irq::PwmIrq<'a>) {
+        self.pwm_irq.set(pwm_irq);
+    }
+
+    /// Registers `client` to be notified when `slice` wraps, and enables
+    /// that slice's wrap interrupt.
+    pub fn set_wrap_client(&self, slice: usize, client: &'a dyn WrapClient) {
+        self.wrap_clients[slice].set(client);
+        self.pwm_irq.map(|irq| irq.enable_interrupt(slice));
+    }
+
+    /// Stages `duty_a`/`duty_b` as `slice`'s next `CC` value, to be written
+    /// to hardware at the slice's next wrap rather than immediately. Enables
+    /// the slice's wrap interrupt so that wrap is seen even when no
+    /// [`WrapClient`] is registered for the slice.
+    pub fn set_duty_cycle(&self, slice: usize, duty_a: u16, duty_b: u16) {
+        let packed = (duty_a as u32) | ((duty_b as u32) << 16);
+        self.pending_duty[slice].set(Some(packed));
+        self.pwm_irq.map(|irq| irq.enable_interrupt(slice));
+    }
+
+    fn apply_pending_duty_cycle(&self, slice: usize) {
+        if let Some(packed) = self.pending_duty[slice].take() {
+            let duty_a = packed & 0xffff;
+            let duty_b = packed >> 16;
+            slice_registers(slice)
+                .cc
+                .write(CC::A.val(duty_a) + CC::B.val(duty_b));
+        }
+    }
+}
+
+impl<'a> pwm_irq::Client for Pwm<'a> {
+    /// Applies `slice`'s staged duty-cycle update, if any, then forwards
+    /// the wrap to whichever capsule registered for it.
+    fn wrapped(&self, slice: usize) {
+        self.apply_pending_duty_cycle(slice);
+        self.wrap_clients[slice].map(|client| client.wrapped());
+    }
+}
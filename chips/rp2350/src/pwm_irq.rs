@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Wrap/counter-compare interrupt support for the PWM driver.
+//!
+//! Each PWM slice can raise an interrupt when its counter wraps (or, in
+//! phase-correct mode, on each direction change), latching a per-slice bit
+//! in `INTR` that must be explicitly cleared. This lets capsules synchronize
+//! software-timed duty updates to the hardware wrap point, instead of only
+//! ever programming a duty cycle that free-runs until the next write.
+//!
+//! This module only owns the shared `INTR`/`INTE`/`INTF`/`INTS` registers;
+//! it is not itself a HIL driver. The chip wires its single [`Client`] slot
+//! to `pwm::Pwm`, which fans per-slice wraps back out to whichever capsule
+//! registered for that slice.
+
+use kernel::utilities::cells::OptionalCell;
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{register_bitfields, register_structs, ReadWrite};
+use kernel::utilities::StaticRef;
+
+/// Number of PWM slices on the RP2350 (slices 0 through 11).
+pub const NUM_SLICES: usize = 12;
+
+register_bitfields![u32,
+    INTR [
+        CH OFFSET(0) NUMBITS(12) []
+    ]
+];
+
+register_structs! {
+    PwmIrqRegisters {
+        (0x000 => intr: ReadWrite<u32, INTR::Register>),
+        (0x004 => inte: ReadWrite<u32, INTR::Register>),
+        (0x008 => intf: ReadWrite<u32, INTR::Register>),
+        (0x00c => ints: ReadWrite<u32, INTR::Register>),
+        (0x010 => @END),
+    }
+}
+
+// Offset of the shared interrupt registers within the PWM register block
+// (they follow the 12 per-slice CSR/DIV/CTR/CC/TOP register groups, 5
+// registers of 4 bytes each -- 0x14 bytes per slice).
+const PWM_IRQ_OFFSET: usize = 0x14 * 12;
+const PWM_BASE_ADDRESS: usize = 0x4009_8000;
+
+const PWM_IRQ_REGISTERS: StaticRef<PwmIrqRegisters> =
+    unsafe { StaticRef::new((PWM_BASE_ADDRESS + PWM_IRQ_OFFSET) as *const PwmIrqRegisters) };
+
+/// A client notified when a PWM slice's counter wraps.
+pub trait Client {
+    /// Called once for each slice whose wrap interrupt fired since the last
+    /// call, in slice-index order.
+    fn wrapped(&self, slice: usize);
+}
+
+pub struct PwmIrq<'a> {
+    registers: StaticRef<PwmIrqRegisters>,
+    client: OptionalCell<&'a dyn Client>,
+}
+
+impl<'a> PwmIrq<'a> {
+    pub const fn new() -> PwmIrq<'a> {
+        PwmIrq {
+            registers: PWM_IRQ_REGISTERS,
+            client: OptionalCell::empty(),
+        }
+    }
+
+    pub fn set_client(&self, client: &'a dyn Client) {
+        self.client.set(client);
+    }
+
+    /// Enables the wrap interrupt for `slice`.
+    pub fn enable_interrupt(&self, slice: usize) {
+        let current = self.registers.inte.read(INTR::CH);
+        self.registers
+            .inte
+            .write(INTR::CH.val(current | (1 << slice)));
+    }
+
+    /// Disables the wrap interrupt for `slice`.
+    pub fn disable_interrupt(&self, slice: usize) {
+        let current = self.registers.inte.read(INTR::CH);
+        self.registers
+            .inte
+            .write(INTR::CH.val(current & !(1 << slice)));
+    }
+
+    /// Services `PWM_IRQ_WRAP`: reads the latched, post-mask `INTS` bits,
+    /// clears each one in `INTR` (write-one-to-clear), and notifies the
+    /// client once per slice that fired, instead of blanket-acknowledging
+    /// the interrupt and discarding it.
+    pub fn handle_interrupt(&self) {
+        let pending = self.registers.ints.read(INTR::CH);
+        if pending == 0 {
+            return;
+        }
+
+        self.registers.intr.write(INTR::CH.val(pending));
+
+        self.client.map(|client| {
+            for slice in 0..NUM_SLICES {
+                if pending & (1 << slice) != 0 {
+                    client.wrapped(slice);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,351 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Calendar date-time layer on top of the RP2350 RTC.
+//!
+//! `rtc::Rtc` exposes the hardware's raw counter view. This module adds the
+//! broken-down calendar view other RP HALs offer via an optional `chrono`
+//! integration: converting to and from year/month/day/weekday/hour/minute/
+//! second fields, validating them (day-of-month bounds, leap years) before
+//! they are ever written to hardware, and an alarm that fires a client
+//! callback when the RTC's match logic hits a configured wall-clock time.
+//! It is exposed through the kernel's `hil::date_time` traits so userspace
+//! can read and set an actual calendar, not just a raw tick count.
+
+use kernel::hil::date_time::{DateTime, DateTimeClient, DateTimeValues, DayOfWeek, Month};
+use kernel::utilities::cells::OptionalCell;
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{register_bitfields, register_structs, ReadWrite};
+use kernel::utilities::StaticRef;
+use kernel::ErrorCode;
+
+register_bitfields![u32,
+    CTRL [
+        RTC_ENABLE OFFSET(0) NUMBITS(1) [],
+        RTC_ACTIVE OFFSET(1) NUMBITS(1) [],
+        LOAD OFFSET(4) NUMBITS(1) []
+    ],
+    SETUP_0 [
+        YEAR OFFSET(12) NUMBITS(12) [],
+        MONTH OFFSET(8) NUMBITS(4) [],
+        DAY OFFSET(0) NUMBITS(5) []
+    ],
+    SETUP_1 [
+        DOTW OFFSET(24) NUMBITS(3) [],
+        HOUR OFFSET(16) NUMBITS(5) [],
+        MIN OFFSET(8) NUMBITS(6) [],
+        SEC OFFSET(0) NUMBITS(6) []
+    ],
+    IRQ_SETUP_0 [
+        MATCH_ENA OFFSET(28) NUMBITS(1) [],
+        YEAR_ENA OFFSET(26) NUMBITS(1) [],
+        MONTH_ENA OFFSET(25) NUMBITS(1) [],
+        DAY_ENA OFFSET(24) NUMBITS(1) [],
+        YEAR OFFSET(12) NUMBITS(12) [],
+        MONTH OFFSET(8) NUMBITS(4) [],
+        DAY OFFSET(0) NUMBITS(5) []
+    ],
+    IRQ_SETUP_1 [
+        DOTW_ENA OFFSET(31) NUMBITS(1) [],
+        HOUR_ENA OFFSET(30) NUMBITS(1) [],
+        MIN_ENA OFFSET(29) NUMBITS(1) [],
+        SEC_ENA OFFSET(28) NUMBITS(1) [],
+        DOTW OFFSET(24) NUMBITS(3) [],
+        HOUR OFFSET(16) NUMBITS(5) [],
+        MIN OFFSET(8) NUMBITS(6) [],
+        SEC OFFSET(0) NUMBITS(6) []
+    ]
+];
+
+register_structs! {
+    RtcRegisters {
+        (0x00 => clkdiv_m1: ReadWrite<u32>),
+        (0x04 => setup_0: ReadWrite<u32, SETUP_0::Register>),
+        (0x08 => setup_1: ReadWrite<u32, SETUP_1::Register>),
+        (0x0c => ctrl: ReadWrite<u32, CTRL::Register>),
+        (0x10 => irq_setup_0: ReadWrite<u32, IRQ_SETUP_0::Register>),
+        (0x14 => irq_setup_1: ReadWrite<u32, IRQ_SETUP_1::Register>),
+        (0x18 => rtc_1: ReadWrite<u32, SETUP_1::Register>),
+        (0x1c => rtc_0: ReadWrite<u32, SETUP_0::Register>),
+        (0x20 => @END),
+    }
+}
+
+const RTC_BASE_ADDRESS: usize = 0x4005_c000;
+const RTC_REGISTERS: StaticRef<RtcRegisters> =
+    unsafe { StaticRef::new(RTC_BASE_ADDRESS as *const RtcRegisters) };
+
+/// Returns whether `year` is a leap year in the proleptic Gregorian
+/// calendar.
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in `month` of `year`, or `None` if `month` is
+/// out of range.
+fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 => Some(if is_leap_year(year) { 29 } else { 28 }),
+        _ => None,
+    }
+}
+
+/// The largest value the hardware's 12-bit `YEAR` field can hold.
+const MAX_YEAR: u16 = 0xfff;
+
+/// Validates that `date` describes a real calendar date and time.
+fn validate(date: &DateTimeValues) -> Result<(), ErrorCode> {
+    if date.year > MAX_YEAR {
+        return Err(ErrorCode::INVAL);
+    }
+    let month = date.month as u8;
+    let max_day = days_in_month(date.year, month).ok_or(ErrorCode::INVAL)?;
+    if date.day < 1 || date.day > max_day {
+        return Err(ErrorCode::INVAL);
+    }
+    if date.hour > 23 || date.minute > 59 || date.seconds > 59 {
+        return Err(ErrorCode::INVAL);
+    }
+    Ok(())
+}
+
+fn day_of_week_to_dotw(day: DayOfWeek) -> u32 {
+    day as u32
+}
+
+fn month_from_number(month: u32) -> Month {
+    match month {
+        1 => Month::January,
+        2 => Month::February,
+        3 => Month::March,
+        4 => Month::April,
+        5 => Month::May,
+        6 => Month::June,
+        7 => Month::July,
+        8 => Month::August,
+        9 => Month::September,
+        10 => Month::October,
+        11 => Month::November,
+        _ => Month::December,
+    }
+}
+
+fn dotw_to_day_of_week(dotw: u32) -> DayOfWeek {
+    match dotw {
+        0 => DayOfWeek::Sunday,
+        1 => DayOfWeek::Monday,
+        2 => DayOfWeek::Tuesday,
+        3 => DayOfWeek::Wednesday,
+        4 => DayOfWeek::Thursday,
+        5 => DayOfWeek::Friday,
+        _ => DayOfWeek::Saturday,
+    }
+}
+
+/// Notified when the RTC's calendar-alarm match fires.
+pub trait AlarmClient {
+    fn alarm(&self);
+}
+
+pub struct RtcDateTime<'a> {
+    registers: StaticRef<RtcRegisters>,
+    date_time_client: OptionalCell<&'a dyn DateTimeClient>,
+    alarm_client: OptionalCell<&'a dyn AlarmClient>,
+}
+
+impl<'a> RtcDateTime<'a> {
+    pub const fn new() -> RtcDateTime<'a> {
+        RtcDateTime {
+            registers: RTC_REGISTERS,
+            date_time_client: OptionalCell::empty(),
+            alarm_client: OptionalCell::empty(),
+        }
+    }
+
+    /// Called from the chip's `RTC_IRQ` handler when the configured alarm
+    /// match fires.
+    pub fn handle_match(&self) {
+        self.alarm_client.map(|client| client.alarm());
+    }
+}
+
+impl<'a> DateTime<'a> for RtcDateTime<'a> {
+    fn get_date_time(&self) -> Result<(), ErrorCode> {
+        // Reads must be taken from `RTC_0`/`RTC_1`, the live, continuously
+        // updating shadow of the counter (as opposed to `SETUP_0/1`, which
+        // only reflect the value most recently loaded into the counter).
+        let rtc_0 = self.registers.rtc_0.extract();
+        let rtc_1 = self.registers.rtc_1.extract();
+
+        let date = DateTimeValues {
+            year: rtc_0.read(SETUP_0::YEAR) as u16,
+            month: month_from_number(rtc_0.read(SETUP_0::MONTH)),
+            day: rtc_0.read(SETUP_0::DAY) as u8,
+            day_of_week: dotw_to_day_of_week(rtc_1.read(SETUP_1::DOTW)),
+            hour: rtc_1.read(SETUP_1::HOUR) as u8,
+            minute: rtc_1.read(SETUP_1::MIN) as u8,
+            seconds: rtc_1.read(SETUP_1::SEC) as u8,
+        };
+
+        self.date_time_client
+            .map(|client| client.get_date_time_done(Ok(date)));
+        Ok(())
+    }
+
+    fn set_date_time(&self, date: DateTimeValues) -> Result<(), ErrorCode> {
+        validate(&date)?;
+
+        self.registers.ctrl.write(CTRL::RTC_ENABLE::CLEAR);
+
+        self.registers.setup_0.write(
+            SETUP_0::YEAR.val(date.year as u32)
+                + SETUP_0::MONTH.val(date.month as u32)
+                + SETUP_0::DAY.val(date.day as u32),
+        );
+        self.registers.setup_1.write(
+            SETUP_1::DOTW.val(day_of_week_to_dotw(date.day_of_week))
+                + SETUP_1::HOUR.val(date.hour as u32)
+                + SETUP_1::MIN.val(date.minute as u32)
+                + SETUP_1::SEC.val(date.seconds as u32),
+        );
+
+        self.registers.ctrl.write(CTRL::LOAD::SET);
+        self.registers.ctrl.write(CTRL::RTC_ENABLE::SET);
+
+        self.date_time_client
+            .map(|client| client.set_date_time_done(Ok(())));
+        Ok(())
+    }
+
+    fn set_client(&self, client: &'a dyn DateTimeClient) {
+        self.date_time_client.set(client);
+    }
+}
+
+/// Arms the RTC's match logic to fire an alarm the next time the clock hits
+/// `date`, notifying `client` when it does.
+impl<'a> RtcDateTime<'a> {
+    pub fn set_alarm_client(&self, client: &'a dyn AlarmClient) {
+        self.alarm_client.set(client);
+    }
+
+    pub fn set_alarm(&self, date: DateTimeValues) -> Result<(), ErrorCode> {
+        validate(&date)?;
+
+        self.registers.irq_setup_0.write(
+            IRQ_SETUP_0::MATCH_ENA::SET
+                + IRQ_SETUP_0::YEAR_ENA::SET
+                + IRQ_SETUP_0::MONTH_ENA::SET
+                + IRQ_SETUP_0::DAY_ENA::SET
+                + IRQ_SETUP_0::YEAR.val(date.year as u32)
+                + IRQ_SETUP_0::MONTH.val(date.month as u32)
+                + IRQ_SETUP_0::DAY.val(date.day as u32),
+        );
+        self.registers.irq_setup_1.write(
+            IRQ_SETUP_1::DOTW_ENA::CLEAR
+                + IRQ_SETUP_1::HOUR_ENA::SET
+                + IRQ_SETUP_1::MIN_ENA::SET
+                + IRQ_SETUP_1::SEC_ENA::SET
+                + IRQ_SETUP_1::HOUR.val(date.hour as u32)
+                + IRQ_SETUP_1::MIN.val(date.minute as u32)
+                + IRQ_SETUP_1::SEC.val(date.seconds as u32),
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: Month, day: u8, hour: u8, minute: u8, seconds: u8) -> DateTimeValues {
+        DateTimeValues {
+            year,
+            month,
+            day,
+            day_of_week: DayOfWeek::Monday,
+            hour,
+            minute,
+            seconds,
+        }
+    }
+
+    #[test]
+    fn leap_years_follow_the_century_rule() {
+        assert!(is_leap_year(2000)); // divisible by 400: leap
+        assert!(!is_leap_year(1900)); // divisible by 100, not 400: not leap
+        assert!(is_leap_year(2024)); // divisible by 4, not 100: leap
+        assert!(!is_leap_year(2023)); // not divisible by 4: not leap
+    }
+
+    #[test]
+    fn february_length_depends_on_leap_year() {
+        assert_eq!(days_in_month(2024, 2), Some(29));
+        assert_eq!(days_in_month(2023, 2), Some(28));
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2000, 2), Some(29));
+    }
+
+    #[test]
+    fn days_in_month_rejects_out_of_range_months() {
+        assert_eq!(days_in_month(2024, 0), None);
+        assert_eq!(days_in_month(2024, 13), None);
+    }
+
+    #[test]
+    fn validate_accepts_leap_day() {
+        assert_eq!(validate(&date(2024, Month::February, 29, 0, 0, 0)), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_leap_day_in_non_leap_year() {
+        assert_eq!(
+            validate(&date(2023, Month::February, 29, 0, 0, 0)),
+            Err(ErrorCode::INVAL)
+        );
+    }
+
+    #[test]
+    fn validate_rejects_day_zero() {
+        assert_eq!(
+            validate(&date(2024, Month::January, 0, 0, 0, 0)),
+            Err(ErrorCode::INVAL)
+        );
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_time() {
+        assert_eq!(
+            validate(&date(2024, Month::January, 1, 24, 0, 0)),
+            Err(ErrorCode::INVAL)
+        );
+        assert_eq!(
+            validate(&date(2024, Month::January, 1, 0, 60, 0)),
+            Err(ErrorCode::INVAL)
+        );
+        assert_eq!(
+            validate(&date(2024, Month::January, 1, 0, 0, 60)),
+            Err(ErrorCode::INVAL)
+        );
+    }
+
+    #[test]
+    fn validate_accepts_max_year() {
+        assert_eq!(
+            validate(&date(MAX_YEAR, Month::January, 1, 0, 0, 0)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn validate_rejects_year_beyond_the_12_bit_field() {
+        assert_eq!(
+            validate(&date(MAX_YEAR + 1, Month::January, 1, 0, 0, 0)),
+            Err(ErrorCode::INVAL)
+        );
+    }
+}
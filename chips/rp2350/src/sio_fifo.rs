@@ -0,0 +1,221 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2025.
+
+//! Inter-core mailbox built on top of the SIO FIFOs.
+//!
+//! The RP2350's two cores each get a one-directional hardware FIFO into the
+//! other (`FIFO_WR`/`FIFO_RD`, gated by `FIFO_ST`'s `VLD`/`RDY` bits). This
+//! module layers two things on top of that raw FIFO:
+//!
+//! - [`launch_core1`], the strict send/echo handshake the boot ROM expects
+//!   before core 1 will start executing at a given entry point.
+//! - [`FifoChannel`], a thin, safe wrapper for ad-hoc messaging between
+//!   cores once both are up. It is generic over the message type so board
+//!   code can define its own small `Copy` command type instead of packing
+//!   and unpacking raw words at every call site.
+//!
+//! Both are board-facing library APIs: deciding when to launch core 1 and
+//! what runs on it, and dispatching received messages out of
+//! `SIO_IRQ_PROC0`/`SIO_IRQ_PROC1`, is a board-level policy choice, not
+//! something this chip-level module calls on its own.
+
+use core::marker::PhantomData;
+
+use kernel::utilities::registers::interfaces::{Readable, Writeable};
+use kernel::utilities::registers::{
+    register_bitfields, register_structs, ReadOnly, ReadWrite, WriteOnly,
+};
+use kernel::utilities::StaticRef;
+
+use crate::gpio::SIO;
+
+register_bitfields![u32,
+    FIFO_ST [
+        /// The RX FIFO is non-empty (there is data for us to read).
+        VLD OFFSET(0) NUMBITS(1) [],
+        /// The TX FIFO is not full (we can push another word).
+        RDY OFFSET(1) NUMBITS(1) [],
+        /// The TX FIFO was written to while full.
+        WOF OFFSET(2) NUMBITS(1) [],
+        /// The RX FIFO was read from while empty.
+        ROE OFFSET(3) NUMBITS(1) [],
+    ]
+];
+
+register_structs! {
+    FifoRegisters {
+        (0x000 => fifo_st: ReadWrite<u32, FIFO_ST::Register>),
+        (0x004 => fifo_wr: WriteOnly<u32>),
+        (0x008 => fifo_rd: ReadOnly<u32>),
+        (0x00c => @END),
+    }
+}
+
+// Offset of the FIFO registers within the SIO register block (see the
+// RP2350 datasheet's SIO register listing: FIFO_ST at 0x50).
+const SIO_BASE: usize = 0xd000_0000;
+const FIFO_OFFSET: usize = 0x50;
+
+const FIFO_REGISTERS: StaticRef<FifoRegisters> =
+    unsafe { StaticRef::new((SIO_BASE + FIFO_OFFSET) as *const FifoRegisters) };
+
+/// Waits for an event with `wfe`. Paired with the `sev` that each FIFO push
+/// or pop issues, so a core blocked on the FIFO is woken promptly rather than
+/// only on its next poll.
+#[cfg(target_arch = "arm")]
+fn wfe() {
+    unsafe {
+        core::arch::asm!("wfe", options(nomem, nostack));
+    }
+}
+
+#[cfg(target_arch = "arm")]
+fn sev() {
+    unsafe {
+        core::arch::asm!("sev", options(nomem, nostack));
+    }
+}
+
+#[cfg(not(target_arch = "arm"))]
+fn wfe() {
+    core::hint::spin_loop();
+}
+
+#[cfg(not(target_arch = "arm"))]
+fn sev() {}
+
+/// Blocks until the TX FIFO has room, then pushes `word`.
+fn fifo_push_blocking(word: u32) {
+    while !FIFO_REGISTERS.fifo_st.is_set(FIFO_ST::RDY) {
+        wfe();
+    }
+    FIFO_REGISTERS.fifo_wr.set(word);
+    sev();
+}
+
+/// Blocks until the RX FIFO has data, then pops it.
+fn fifo_pop_blocking() -> u32 {
+    while !FIFO_REGISTERS.fifo_st.is_set(FIFO_ST::VLD) {
+        wfe();
+    }
+    let word = FIFO_REGISTERS.fifo_rd.get();
+    sev();
+    word
+}
+
+/// Performs the hardware launch handshake that starts core 1 executing at
+/// `entry`, using `stack` as its initial stack.
+///
+/// Core 1 (held in the boot ROM's wait loop after reset) expects core 0 to
+/// push the sequence `[0, 0, 1, vector_table_addr, initial_sp, entry_point]`
+/// one word at a time, echoing each word back before the next is sent. If at
+/// any point core 1 echoes back something other than the word just sent, the
+/// sequence must restart from the first `0` -- this rejects stale echoes
+/// left over from, e.g., a previous failed attempt or a warm reset.
+pub fn launch_core1(stack: &'static mut [usize], entry: fn()) {
+    extern "C" {
+        // Provided by the linker script: the base of the vector table this
+        // core itself booted from, which core 1 also uses.
+        static _svectors: usize;
+    }
+
+    let vector_table_addr = unsafe { &_svectors as *const usize as usize };
+    let initial_sp = stack.as_ptr() as usize + stack.len() * core::mem::size_of::<usize>();
+    let entry_point = entry as usize;
+
+    let sequence = [0, 0, 1, vector_table_addr, initial_sp, entry_point];
+
+    'restart: loop {
+        // Drain anything stale left in the RX FIFO before we start, so an
+        // old echo can't be mistaken for this attempt's.
+        while FIFO_REGISTERS.fifo_st.is_set(FIFO_ST::VLD) {
+            let _ = FIFO_REGISTERS.fifo_rd.get();
+        }
+
+        for &word in &sequence {
+            fifo_push_blocking(word as u32);
+            let echo = fifo_pop_blocking();
+            if echo != word as u32 {
+                continue 'restart;
+            }
+        }
+
+        break;
+    }
+}
+
+/// A value that can be carried as a single word over the inter-core FIFO.
+///
+/// Implemented for `u32` directly. Board code can implement this for its
+/// own small `Copy` command type (an enum of core-1 requests, a packed
+/// struct, ...) to get a statically typed [`FifoChannel`] instead of
+/// juggling raw words at every call site.
+pub trait FifoMessage: Copy {
+    fn into_word(self) -> u32;
+    fn from_word(word: u32) -> Self;
+}
+
+impl FifoMessage for u32 {
+    fn into_word(self) -> u32 {
+        self
+    }
+
+    fn from_word(word: u32) -> Self {
+        word
+    }
+}
+
+/// A typed, safe handle to the SIO inter-core FIFO for general-purpose
+/// messaging, used once both cores are running.
+///
+/// This intentionally does not share state with [`launch_core1`]: the launch
+/// handshake is a one-shot protocol that runs to completion before any other
+/// code touches the FIFO, so there is no risk of the two racing in practice.
+pub struct FifoChannel<T: FifoMessage = u32> {
+    message: PhantomData<T>,
+}
+
+impl SIO {
+    /// Starts core 1 executing `entry` with `stack` as its initial stack,
+    /// via the SIO FIFO launch handshake.
+    pub fn launch_core1(&self, stack: &'static mut [usize], entry: fn()) {
+        launch_core1(stack, entry)
+    }
+
+    /// Returns a handle to the inter-core mailbox, for sending and receiving
+    /// messages of type `T` once both cores are running.
+    pub fn fifo<T: FifoMessage>(&self) -> FifoChannel<T> {
+        FifoChannel::new()
+    }
+}
+
+impl<T: FifoMessage> FifoChannel<T> {
+    pub const fn new() -> FifoChannel<T> {
+        FifoChannel {
+            message: PhantomData,
+        }
+    }
+
+    /// Pushes `message` to the other core without blocking. Returns `false`
+    /// if the TX FIFO is full and the message was not sent.
+    pub fn try_send(&self, message: T) -> bool {
+        if !FIFO_REGISTERS.fifo_st.is_set(FIFO_ST::RDY) {
+            return false;
+        }
+        FIFO_REGISTERS.fifo_wr.set(message.into_word());
+        sev();
+        true
+    }
+
+    /// Pops a message from the other core without blocking, if one is
+    /// available.
+    pub fn try_recv(&self) -> Option<T> {
+        if !FIFO_REGISTERS.fifo_st.is_set(FIFO_ST::VLD) {
+            return None;
+        }
+        let word = FIFO_REGISTERS.fifo_rd.get();
+        sev();
+        Some(T::from_word(word))
+    }
+}